@@ -78,6 +78,69 @@ impl Palette {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Overwrites the `colors.len()` palette entries starting at
+    /// `first_color`, leaving the rest of the palette untouched.
+    ///
+    /// Returns an error instead of letting SDL clamp the range if
+    /// `first_color + colors.len()` exceeds [`Palette::len`].
+    #[doc(alias = "SDL_SetPaletteColors")]
+    pub fn set_colors(&mut self, colors: &[Color], first_color: usize) -> Result<(), Error> {
+        if colors.is_empty() {
+            return Ok(());
+        }
+
+        let end = first_color.saturating_add(colors.len());
+        if end > self.len() {
+            return Err(Error(format!(
+                "color range {}..{} is out of bounds for a palette of length {}",
+                first_color,
+                end,
+                self.len()
+            )));
+        }
+
+        let result = unsafe {
+            let mut raw_colors: Vec<sys::pixels::SDL_Color> =
+                colors.iter().map(|color| color.raw()).collect();
+
+            let pal_ptr = (&mut raw_colors[0]) as *mut sys::pixels::SDL_Color;
+
+            sys::pixels::SDL_SetPaletteColors(
+                self.raw,
+                pal_ptr,
+                first_color as ::libc::c_int,
+                colors.len() as ::libc::c_int,
+            )
+        };
+
+        if !result {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Overwrites a single palette entry. Shorthand for
+    /// [`Palette::set_colors`] with a one-element slice.
+    #[doc(alias = "SDL_SetPaletteColors")]
+    pub fn set_color(&mut self, index: usize, color: Color) -> Result<(), Error> {
+        self.set_colors(&[color], index)
+    }
+
+    /// Returns the color at `index`, or `None` if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Color> {
+        if index >= self.len() {
+            return None;
+        }
+
+        unsafe { Some(Color::from(*(*self.raw).colors.add(index))) }
+    }
+
+    /// Returns an iterator over every color in the palette, in order.
+    pub fn colors(&self) -> impl Iterator<Item = Color> + '_ {
+        (0..self.len()).map(move |i| self.get(i).unwrap())
+    }
 }
 
 impl Drop for Palette {
@@ -91,6 +154,177 @@ impl Drop for Palette {
 
 impl_raw_accessors!((Palette, *mut sys::pixels::SDL_Palette));
 
+/// One box in a median-cut quantizer: a subset of the input colors that
+/// will either be split further or turned into a single palette entry.
+struct ColorBox {
+    colors: Vec<Color>,
+}
+
+impl ColorBox {
+    fn channel(color: Color, channel: usize) -> u8 {
+        match channel {
+            0 => color.r,
+            1 => color.g,
+            2 => color.b,
+            _ => color.a,
+        }
+    }
+
+    fn channel_range(&self, channel: usize) -> u8 {
+        let min = self.colors.iter().map(|&c| Self::channel(c, channel)).min().unwrap();
+        let max = self.colors.iter().map(|&c| Self::channel(c, channel)).max().unwrap();
+        max - min
+    }
+
+    fn longest_channel(&self) -> usize {
+        (0..4).max_by_key(|&channel| self.channel_range(channel)).unwrap()
+    }
+
+    /// The alpha-weighted average color of this box: low-alpha colors
+    /// contribute less to the averaged R/G/B so that near-transparent
+    /// noise doesn't skew the result.
+    fn average(&self) -> Color {
+        let total_weight: f64 = self.colors.iter().map(|c| c.a as f64 / 255.0).sum();
+        let n = self.colors.len() as f64;
+
+        let (sum_r, sum_g, sum_b) = self.colors.iter().fold((0.0, 0.0, 0.0), |(r, g, b), c| {
+            let w = if total_weight > 0.0 { c.a as f64 / 255.0 } else { 1.0 };
+            (r + c.r as f64 * w, g + c.g as f64 * w, b + c.b as f64 * w)
+        });
+        let divisor = if total_weight > 0.0 { total_weight } else { n };
+        let (r, g, b) = (sum_r / divisor, sum_g / divisor, sum_b / divisor);
+
+        let a = self.colors.iter().map(|c| c.a as f64).sum::<f64>() / n;
+
+        Color::RGBA(
+            r.round() as u8,
+            g.round() as u8,
+            b.round() as u8,
+            a.round() as u8,
+        )
+    }
+}
+
+/// Weighted squared distance between two colors, used to find the
+/// nearest palette entry for a source pixel. The channel weights track
+/// perceptual sensitivity (green matters most, blue least).
+fn weighted_distance(a: Color, b: Color) -> f64 {
+    let dr = a.r as f64 - b.r as f64;
+    let dg = a.g as f64 - b.g as f64;
+    let db = a.b as f64 - b.b as f64;
+    let da = a.a as f64 - b.a as f64;
+    0.5 * dr * dr + 1.0 * dg * dg + 0.45 * db * db + 0.625 * da * da
+}
+
+fn nearest_palette_index(color: Color, palette: &[Color]) -> u8 {
+    palette
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| {
+            weighted_distance(color, **a)
+                .partial_cmp(&weighted_distance(color, **b))
+                .unwrap()
+        })
+        .map(|(i, _)| i as u8)
+        .unwrap()
+}
+
+/// Reduces a true-color image to an indexed image using median-cut color
+/// quantization: starting from a single box holding every input color,
+/// repeatedly splits the box with the largest channel range along its
+/// longest channel until there are `max_colors` boxes (one fewer when a
+/// transparent entry is reserved, see below) or none left large enough to
+/// split, then maps every pixel to its nearest resulting palette entry.
+///
+/// Fully-transparent pixels carry no visible color information, so they
+/// are all collapsed onto a single reserved palette entry rather than
+/// spread across boxes by their (often arbitrary) RGB channels.
+pub fn quantize(pixels: &[Color], max_colors: usize) -> (Palette, Vec<u8>) {
+    let max_colors = max_colors.max(1);
+
+    if pixels.is_empty() {
+        // `Palette::with_colors(&[])` indexes into its first element to
+        // build the FFI call, so it panics on an empty slice; `new` doesn't.
+        return (
+            Palette::new(0).expect("failed to create empty palette"),
+            Vec::new(),
+        );
+    }
+
+    let transparent = Color::RGBA(0, 0, 0, 0);
+    let mut opaque = Vec::new();
+    let mut has_transparent = false;
+    for &color in pixels {
+        if color.a == 0 {
+            has_transparent = true;
+        } else {
+            opaque.push(color);
+        }
+    }
+
+    // The transparent entry (if any) counts against `max_colors` too, so
+    // the opaque boxes only get what's left over — which can be zero, in
+    // which case every pixel (even opaque ones) collapses onto the single
+    // transparent/placeholder entry rather than exceeding the cap.
+    let box_budget = max_colors - usize::from(has_transparent);
+
+    let mut distinct = opaque.clone();
+    distinct.sort_by_key(|c| (c.r, c.g, c.b, c.a));
+    distinct.dedup();
+
+    let mut final_colors = if opaque.is_empty() || box_budget == 0 {
+        Vec::new()
+    } else if distinct.len() <= box_budget {
+        distinct
+    } else {
+        let mut boxes = vec![ColorBox { colors: opaque }];
+
+        while boxes.len() < box_budget {
+            let split_at = boxes
+                .iter()
+                .enumerate()
+                .filter(|(_, b)| b.colors.len() > 1)
+                .max_by_key(|(_, b)| b.channel_range(b.longest_channel()))
+                .map(|(i, _)| i);
+
+            let Some(i) = split_at else { break };
+
+            let channel = boxes[i].longest_channel();
+            let mut colors = std::mem::take(&mut boxes[i].colors);
+            colors.sort_by_key(|&c| ColorBox::channel(c, channel));
+
+            let upper = colors.split_off(colors.len() / 2);
+            boxes[i].colors = colors;
+            boxes.push(ColorBox { colors: upper });
+        }
+
+        boxes.iter().map(ColorBox::average).collect()
+    };
+
+    let transparent_index = if has_transparent {
+        final_colors.push(transparent);
+        Some((final_colors.len() - 1) as u8)
+    } else {
+        None
+    };
+
+    let palette =
+        Palette::with_colors(&final_colors).expect("failed to create quantized palette");
+
+    let indices = pixels
+        .iter()
+        .map(|&color| {
+            if color.a == 0 || box_budget == 0 {
+                transparent_index.unwrap()
+            } else {
+                nearest_palette_index(color, &final_colors)
+            }
+        })
+        .collect();
+
+    (palette, indices)
+}
+
 #[test]
 fn create_palette() {
     let colors: Vec<_> = (0..0xff).map(|u| Color::RGB(u, 0, 0xff - u)).collect();
@@ -100,6 +334,73 @@ fn create_palette() {
     assert!(palette.len() == 255);
 }
 
+#[test]
+fn palette_get_and_colors() {
+    let colors: Vec<_> = (0..4).map(|u| Color::RGB(u * 10, 0, 0)).collect();
+    let palette = Palette::with_colors(&colors).unwrap();
+
+    assert_eq!(palette.get(2), Some(Color::RGB(20, 0, 0)));
+    assert_eq!(palette.get(4), None);
+    assert_eq!(palette.colors().collect::<Vec<_>>(), colors);
+}
+
+#[test]
+fn palette_set_colors() {
+    let mut palette = Palette::with_colors(&[Color::BLACK; 4]).unwrap();
+
+    palette
+        .set_colors(&[Color::RED, Color::GREEN], 1)
+        .unwrap();
+
+    assert_eq!(
+        palette.colors().collect::<Vec<_>>(),
+        vec![Color::BLACK, Color::RED, Color::GREEN, Color::BLACK]
+    );
+
+    palette.set_color(0, Color::BLUE).unwrap();
+    assert_eq!(palette.get(0), Some(Color::BLUE));
+}
+
+#[test]
+fn palette_set_colors_out_of_range() {
+    let mut palette = Palette::with_colors(&[Color::BLACK; 4]).unwrap();
+
+    assert!(palette.set_colors(&[Color::RED, Color::GREEN], 3).is_err());
+    assert!(palette.set_color(4, Color::RED).is_err());
+}
+
+#[test]
+fn quantize_empty_input() {
+    let (palette, indices) = quantize(&[], 16);
+
+    assert_eq!(palette.len(), 0);
+    assert!(indices.is_empty());
+}
+
+#[test]
+fn quantize_all_transparent() {
+    let pixels = vec![Color::RGBA(0, 0, 0, 0); 4];
+
+    let (palette, indices) = quantize(&pixels, 16);
+
+    assert_eq!(palette.len(), 1);
+    assert_eq!(palette.get(0), Some(Color::RGBA(0, 0, 0, 0)));
+    assert_eq!(indices, vec![0, 0, 0, 0]);
+}
+
+#[test]
+fn quantize_fewer_distinct_than_max_colors() {
+    let pixels = vec![Color::RED, Color::GREEN, Color::RED, Color::BLUE];
+
+    let (palette, indices) = quantize(&pixels, 16);
+
+    assert_eq!(palette.len(), 3);
+    let colors: Vec<_> = palette.colors().collect();
+    for (&pixel, &index) in pixels.iter().zip(&indices) {
+        assert_eq!(colors[index as usize], pixel);
+    }
+}
+
 #[test]
 fn pixel_format_enum_conversions() {
     // Test round-trip conversions
@@ -134,6 +435,106 @@ fn pixel_format_enum_supports_alpha() {
     assert!(!PixelFormatEnum::RGB24.as_pixel_format().supports_alpha());
 }
 
+#[test]
+fn pixel_format_indexed() {
+    let format = PixelFormatEnum::Index8.as_pixel_format();
+
+    assert!(format.is_indexed());
+    assert!(!format.is_packed());
+    assert!(!format.is_array());
+    assert!(!format.is_fourcc());
+    assert_eq!(format.pixel_type(), PixelType::Index8);
+    assert_eq!(format.bits_per_pixel(), 8);
+    assert_eq!(format.bytes_per_pixel(), 1);
+}
+
+#[test]
+fn pixel_format_packed_true_color() {
+    let format = PixelFormatEnum::RGBA8888.as_pixel_format();
+
+    assert!(format.is_packed());
+    assert!(!format.is_indexed());
+    assert!(!format.is_fourcc());
+    assert!(format.has_alpha());
+    assert_eq!(format.bits_per_pixel(), 32);
+    assert_eq!(format.bytes_per_pixel(), 4);
+
+    assert!(!PixelFormatEnum::RGB24.as_pixel_format().has_alpha());
+}
+
+#[test]
+fn pixel_format_fourcc() {
+    let format = PixelFormatEnum::YV12.as_pixel_format();
+
+    assert!(format.is_fourcc());
+    assert!(!format.is_indexed());
+    assert!(!format.is_packed());
+    assert!(!format.is_array());
+    assert_eq!(format.pixel_type(), PixelType::Unknown);
+    assert_eq!(format.pixel_order(), PixelOrder::None);
+    assert_eq!(format.pixel_layout(), PackedLayout::None);
+}
+
+#[test]
+fn color_to_u32_with_palette_round_trip() {
+    let format = PixelFormatEnum::Index8.as_pixel_format();
+    let mut palette = Palette::with_colors(&[Color::BLACK; 4]).unwrap();
+    palette.set_color(2, Color::RED).unwrap();
+
+    let pixel = Color::RED.to_u32_with_palette(&format, &palette);
+    assert_eq!(pixel, 2);
+
+    let color = Color::from_index(&format, &palette, 2);
+    assert_eq!(color, Color::RED);
+}
+
+#[test]
+fn color_premultiply_round_trip() {
+    let color = Color::RGBA(200, 100, 50, 128);
+
+    // 8-bit premultiplication is lossy: red 200 * 128/255 rounds to 100,
+    // and 100 / (128/255) rounds back to 199.
+    assert_eq!(color.premultiply(), Color::RGBA(100, 50, 25, 128));
+    assert_eq!(
+        color.premultiply().unpremultiply(),
+        Color::RGBA(199, 100, 50, 128)
+    );
+    assert_eq!(
+        Color::RGBA(10, 20, 30, 0).premultiply(),
+        Color::RGBA(0, 0, 0, 0)
+    );
+}
+
+#[test]
+fn color_over_compositing() {
+    let opaque_red = Color::RGBA(255, 0, 0, 255);
+    let background = Color::RGBA(0, 255, 0, 255);
+
+    // Fully opaque foreground completely hides the background.
+    assert_eq!(opaque_red.over(background), opaque_red);
+
+    // Fully transparent foreground leaves the background untouched.
+    assert_eq!(Color::RGBA(255, 0, 0, 0).over(background), background);
+}
+
+#[test]
+fn color_lerp() {
+    let start = Color::RGBA(0, 0, 0, 0);
+    let end = Color::RGBA(200, 100, 50, 255);
+
+    assert_eq!(start.lerp(end, 0.0), start);
+    assert_eq!(start.lerp(end, 1.0), end);
+}
+
+#[test]
+fn color_linear_round_trip() {
+    let color = Color::RGBA(200, 100, 50, 128);
+    let linear = color.to_linear();
+
+    assert_eq!(Color::from_linear(linear), color);
+}
+
+#[repr(C)]
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct Color {
     pub r: u8,
@@ -142,6 +543,15 @@ pub struct Color {
     pub a: u8,
 }
 
+// `Color` is `#[repr(C)]` with four `u8` fields, which is layout-compatible
+// with `SDL_Color` and with packed RGBA8888 pixel buffers, which would make
+// it a sound `bytemuck::Pod`/`Zeroable` impl for a zero-copy `cast_slice`
+// pair. That's deferred rather than added behind `#[cfg(feature =
+// "bytemuck")]`: this crate's Cargo.toml isn't available to declare
+// `bytemuck` as an optional dependency and wire up the matching feature in
+// this tree, and a feature-gated impl nothing can ever turn on is dead code.
+// Add it once the manifest side can be wired up alongside it.
+
 impl Color {
     #[inline]
     #[allow(non_snake_case)]
@@ -187,10 +597,148 @@ impl Color {
         Color::RGBA(r, g, b, a)
     }
 
+    /// Like [`Color::to_u32`], but maps `self` through `palette` instead of
+    /// treating `format` as true-color, for indexed formats (e.g.
+    /// `PixelFormatEnum::Index8`).
+    #[doc(alias = "SDL_MapRGBA")]
+    pub fn to_u32_with_palette(self, format: &PixelFormat, palette: &Palette) -> u32 {
+        unsafe {
+            sys::pixels::SDL_MapRGBA(
+                format.pixel_format_details(),
+                palette.raw,
+                self.r,
+                self.g,
+                self.b,
+                self.a,
+            )
+        }
+    }
+
+    /// Like [`Color::from_u32`], but maps `pixel` through `palette` as an
+    /// index rather than treating it as packed true-color data.
+    #[doc(alias = "SDL_GetRGBA")]
+    pub fn from_index(format: &PixelFormat, palette: &Palette, pixel: u32) -> Color {
+        let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+
+        unsafe {
+            sys::pixels::SDL_GetRGBA(
+                pixel,
+                format.pixel_format_details(),
+                palette.raw,
+                &mut r,
+                &mut g,
+                &mut b,
+                &mut a,
+            )
+        };
+        Color::RGBA(r, g, b, a)
+    }
+
     pub fn invert(self) -> Color {
         Color::RGBA(255 - self.r, 255 - self.g, 255 - self.b, 255 - self.a)
     }
 
+    /// Alpha-composites `self` over `background` using straight-alpha
+    /// "source over" blending.
+    pub fn over(self, background: Color) -> Color {
+        let sa = self.a as f32 / 255.0;
+        let da = background.a as f32 / 255.0;
+        let out_a = sa + da * (1.0 - sa);
+
+        if out_a <= 0.0 {
+            return Color::RGBA(0, 0, 0, 0);
+        }
+
+        let blend = |sc: u8, dc: u8| -> u8 {
+            let sc = sc as f32 / 255.0;
+            let dc = dc as f32 / 255.0;
+            let out = (sc * sa + dc * da * (1.0 - sa)) / out_a;
+            (out * 255.0).round().clamp(0.0, 255.0) as u8
+        };
+
+        Color::RGBA(
+            blend(self.r, background.r),
+            blend(self.g, background.g),
+            blend(self.b, background.b),
+            (out_a * 255.0).round().clamp(0.0, 255.0) as u8,
+        )
+    }
+
+    /// Converts this straight-alpha color to premultiplied alpha.
+    pub fn premultiply(self) -> Color {
+        let a = self.a as f32 / 255.0;
+        let mul = |c: u8| (c as f32 * a).round() as u8;
+        Color::RGBA(mul(self.r), mul(self.g), mul(self.b), self.a)
+    }
+
+    /// Converts this premultiplied-alpha color back to straight alpha.
+    /// Reverses [`Color::premultiply`] up to the rounding that premultiplying
+    /// into 8-bit channels loses, so a round trip can differ by a step.
+    pub fn unpremultiply(self) -> Color {
+        if self.a == 0 {
+            return Color::RGBA(0, 0, 0, 0);
+        }
+        let a = self.a as f32 / 255.0;
+        let div = |c: u8| (c as f32 / a).round().clamp(0.0, 255.0) as u8;
+        Color::RGBA(div(self.r), div(self.g), div(self.b), self.a)
+    }
+
+    /// Linearly interpolates every channel, including alpha, from `self`
+    /// (`t = 0.0`) to `other` (`t = 1.0`).
+    pub fn lerp(self, other: Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Color::RGBA(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+            mix(self.a, other.a),
+        )
+    }
+
+    /// Converts this color's R/G/B channels from sRGB to linear light,
+    /// returning `[r, g, b, a]` in the `0.0..=1.0` range. Alpha is copied
+    /// through unchanged, since it isn't gamma-encoded.
+    pub fn to_linear(self) -> [f32; 4] {
+        fn channel_to_linear(c: u8) -> f32 {
+            let c = c as f32 / 255.0;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+
+        [
+            channel_to_linear(self.r),
+            channel_to_linear(self.g),
+            channel_to_linear(self.b),
+            self.a as f32 / 255.0,
+        ]
+    }
+
+    /// Builds a `Color` from linear-light `[r, g, b, a]` channels in the
+    /// `0.0..=1.0` range, applying the sRGB transfer function. The
+    /// inverse of [`Color::to_linear`].
+    pub fn from_linear(linear: [f32; 4]) -> Color {
+        fn channel_from_linear(c: f32) -> u8 {
+            let c = c.clamp(0.0, 1.0);
+            let srgb = if c <= 0.0031308 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            };
+            (srgb * 255.0).round() as u8
+        }
+
+        Color::RGBA(
+            channel_from_linear(linear[0]),
+            channel_from_linear(linear[1]),
+            channel_from_linear(linear[2]),
+            (linear[3].clamp(0.0, 1.0) * 255.0).round() as u8,
+        )
+    }
+
     #[inline]
     pub const fn rgb(self) -> (u8, u8, u8) {
         (self.r, self.g, self.b)
@@ -312,6 +860,14 @@ pub enum PixelFormatEnum {
     YVYU = SDL_PixelFormat::YVYU.0 as isize,
 }
 
+// `PixelFormat` deliberately does not carry an attached `Palette` (e.g. a
+// `with_palette` constructor). Storing a palette's raw pointer directly on
+// this struct would be unsound: `PixelFormat` has no lifetime parameter to
+// tie that pointer's validity to the source `Palette`'s, so a
+// `PixelFormat` could outlive the `Palette` it was built from and leave
+// `Color::to_u32`/`Color::from_u32` dereferencing freed memory. Use
+// [`Color::to_u32_with_palette`]/[`Color::from_index`] instead, which take
+// `&Palette` per call and borrow-check the lifetime correctly.
 pub struct PixelFormat {
     raw: SDL_PixelFormat,
 }
@@ -319,7 +875,7 @@ pub struct PixelFormat {
 impl PixelFormatEnum {
     pub fn as_pixel_format(&self) -> PixelFormat {
         PixelFormat {
-            raw: SDL_PixelFormat(*self as i32)
+            raw: SDL_PixelFormat(*self as i32),
         }
     }
 }
@@ -395,108 +951,302 @@ impl PixelFormat {
         }
     }
 
-    #[allow(clippy::match_same_arms)]
     pub fn byte_size_of_pixels(self, num_of_pixels: usize) -> usize {
         match self.raw {
-            SDL_PixelFormat::RGB332 => num_of_pixels,
-            SDL_PixelFormat::XRGB4444
-            | SDL_PixelFormat::XRGB1555
-            | SDL_PixelFormat::XBGR1555
-            | SDL_PixelFormat::ARGB4444
-            | SDL_PixelFormat::RGBA4444
-            | SDL_PixelFormat::ABGR4444
-            | SDL_PixelFormat::BGRA4444
-            | SDL_PixelFormat::ARGB1555
-            | SDL_PixelFormat::RGBA5551
-            | SDL_PixelFormat::ABGR1555
-            | SDL_PixelFormat::BGRA5551
-            | SDL_PixelFormat::RGB565
-            | SDL_PixelFormat::BGR565 => num_of_pixels * 2,
-            SDL_PixelFormat::RGB24 | SDL_PixelFormat::BGR24 => num_of_pixels * 3,
-            SDL_PixelFormat::XRGB8888
-            | SDL_PixelFormat::RGBX8888
-            | SDL_PixelFormat::XBGR8888
-            | SDL_PixelFormat::BGRX8888
-            | SDL_PixelFormat::ARGB8888
-            | SDL_PixelFormat::RGBA8888
-            | SDL_PixelFormat::ABGR8888
-            | SDL_PixelFormat::BGRA8888
-            | SDL_PixelFormat::ARGB2101010 => num_of_pixels * 4,
-            // YUV formats
+            // YUV is 4:2:0: U and V have half the width and height of Y.
             // FIXME: rounding error here?
             SDL_PixelFormat::YV12 | SDL_PixelFormat::IYUV => num_of_pixels / 2 * 3,
-            SDL_PixelFormat::YUY2 | SDL_PixelFormat::UYVY | SDL_PixelFormat::YVYU => {
-                num_of_pixels * 2
-            }
-            // Unsupported formats
-            SDL_PixelFormat::INDEX8 => num_of_pixels,
-            SDL_PixelFormat::UNKNOWN
-            | SDL_PixelFormat::INDEX1LSB
-            | SDL_PixelFormat::INDEX1MSB
-            | SDL_PixelFormat::INDEX4LSB
-            | SDL_PixelFormat::INDEX4MSB
-            | _ => panic!("not supported format: {:?}", self),
+            _ => self.packed_byte_size(num_of_pixels),
         }
     }
 
-    #[allow(clippy::match_same_arms)]
     pub fn byte_size_per_pixel(self) -> usize {
         match self.raw {
-            SDL_PixelFormat::RGB332 => 1,
-            SDL_PixelFormat::XRGB4444
-            | SDL_PixelFormat::XRGB1555
-            | SDL_PixelFormat::XBGR1555
-            | SDL_PixelFormat::ARGB4444
-            | SDL_PixelFormat::RGBA4444
-            | SDL_PixelFormat::ABGR4444
-            | SDL_PixelFormat::BGRA4444
-            | SDL_PixelFormat::ARGB1555
-            | SDL_PixelFormat::RGBA5551
-            | SDL_PixelFormat::ABGR1555
-            | SDL_PixelFormat::BGRA5551
-            | SDL_PixelFormat::RGB565
-            | SDL_PixelFormat::BGR565 => 2,
-            SDL_PixelFormat::RGB24 | SDL_PixelFormat::BGR24 => 3,
-            SDL_PixelFormat::XRGB8888
-            | SDL_PixelFormat::RGBX8888
-            | SDL_PixelFormat::XBGR8888
-            | SDL_PixelFormat::BGRX8888
-            | SDL_PixelFormat::ARGB8888
-            | SDL_PixelFormat::RGBA8888
-            | SDL_PixelFormat::ABGR8888
-            | SDL_PixelFormat::BGRA8888
-            | SDL_PixelFormat::ARGB2101010 => 4,
-            // YUV formats
             SDL_PixelFormat::YV12 | SDL_PixelFormat::IYUV => 1,
-            SDL_PixelFormat::YUY2 | SDL_PixelFormat::UYVY | SDL_PixelFormat::YVYU => 2,
-            // Unsupported formats
-            SDL_PixelFormat::INDEX8 => 1,
-            SDL_PixelFormat::UNKNOWN
-            | SDL_PixelFormat::INDEX1LSB
-            | SDL_PixelFormat::INDEX1MSB
-            | SDL_PixelFormat::INDEX4LSB
-            | SDL_PixelFormat::INDEX4MSB
-            | _ => panic!("not supported format: {:?}", self),
+            _ => self.packed_byte_size(1).max(1),
+        }
+    }
+
+    /// Byte size of `num_of_pixels` pixels of this (non-YUV) format.
+    ///
+    /// `bytes_per_pixel()` decodes `v & 0xFF`, which SDL encodes as `0` for
+    /// sub-byte indexed formats like INDEX1/INDEX4 — multiplying straight
+    /// through would silently under-report the size of a buffer holding
+    /// such pixels. Bit-pack those formats instead of falling through.
+    fn packed_byte_size(self, num_of_pixels: usize) -> usize {
+        let bits_per_pixel = self.bits_per_pixel() as usize;
+        if !self.is_fourcc() && bits_per_pixel > 0 && bits_per_pixel < 8 {
+            (num_of_pixels * bits_per_pixel + 7) / 8
+        } else {
+            num_of_pixels * self.bytes_per_pixel() as usize
         }
     }
 
     pub fn supports_alpha(self) -> bool {
-        matches!(
-            self.raw,
-            SDL_PixelFormat::ARGB4444
-                | SDL_PixelFormat::ARGB1555
-                | SDL_PixelFormat::ARGB8888
-                | SDL_PixelFormat::ARGB2101010
-                | SDL_PixelFormat::ABGR4444
-                | SDL_PixelFormat::ABGR1555
-                | SDL_PixelFormat::ABGR8888
-                | SDL_PixelFormat::BGRA4444
-                | SDL_PixelFormat::BGRA5551
-                | SDL_PixelFormat::BGRA8888
-                | SDL_PixelFormat::RGBA4444
-                | SDL_PixelFormat::RGBA5551
-                | SDL_PixelFormat::RGBA8888
-        )
+        self.has_alpha()
+    }
+
+    /// Raw bits of the underlying `SDL_PixelFormat` value, decoded
+    /// bit-by-bit by the `pixel_*`/`is_*`/`*_per_pixel` family below. See
+    /// `SDL_DEFINE_PIXELFORMAT` in SDL's headers for the layout.
+    fn bits(self) -> u32 {
+        self.raw.0 as u32
+    }
+
+    /// Whether this value is an opaque FourCC (e.g. for planar/packed YUV
+    /// data) rather than one SDL encodes as type/order/layout/bpp.
+    #[doc(alias = "SDL_ISPIXELFORMAT_FOURCC")]
+    pub fn is_fourcc(self) -> bool {
+        let bits = self.bits();
+        bits != 0 && ((bits >> 28) & 0x0F) != 1
+    }
+
+    /// The pixel type (indexed, packed, or array), decoded from the
+    /// format's type nibble. Always `PixelType::Unknown` for FourCC
+    /// formats.
+    pub fn pixel_type(self) -> PixelType {
+        if self.is_fourcc() {
+            return PixelType::Unknown;
+        }
+        PixelType::from_bits((self.bits() >> 24) & 0x0F)
+    }
+
+    /// The channel order, decoded according to whether [`Self::pixel_type`]
+    /// is packed or array-based. `PixelOrder::None` for indexed or FourCC
+    /// formats.
+    pub fn pixel_order(self) -> PixelOrder {
+        let order_bits = (self.bits() >> 20) & 0x0F;
+        match self.pixel_type() {
+            PixelType::Packed8 | PixelType::Packed16 | PixelType::Packed32 => {
+                PixelOrder::Packed(PackedOrder::from_bits(order_bits))
+            }
+            PixelType::ArrayU8
+            | PixelType::ArrayU16
+            | PixelType::ArrayU32
+            | PixelType::ArrayF16
+            | PixelType::ArrayF32 => PixelOrder::Array(ArrayOrder::from_bits(order_bits)),
+            _ => PixelOrder::None,
+        }
+    }
+
+    /// The bit layout of a packed format (e.g. 5-5-5-1). `PackedLayout::None`
+    /// for non-packed formats.
+    pub fn pixel_layout(self) -> PackedLayout {
+        match self.pixel_type() {
+            PixelType::Packed8 | PixelType::Packed16 | PixelType::Packed32 => {
+                PackedLayout::from_bits((self.bits() >> 16) & 0x0F)
+            }
+            _ => PackedLayout::None,
+        }
+    }
+
+    /// Bits used per pixel, decoded from the format value. Meaningless for
+    /// FourCC formats, which don't encode a bit depth in this field (e.g.
+    /// YV12 decodes to a nonsensical 86 "bits per pixel" here) — use
+    /// [`Self::bytes_per_pixel`] instead for those.
+    pub fn bits_per_pixel(self) -> u8 {
+        ((self.bits() >> 8) & 0xFF) as u8
+    }
+
+    /// Bytes used per pixel, decoded from the format value. For FourCC
+    /// formats this is a format-specific constant rather than a decoded
+    /// field, since FourCC formats don't encode it in the usual bits.
+    pub fn bytes_per_pixel(self) -> u8 {
+        if self.is_fourcc() {
+            match self.raw {
+                SDL_PixelFormat::YUY2 | SDL_PixelFormat::UYVY | SDL_PixelFormat::YVYU => 2,
+                _ => 1,
+            }
+        } else {
+            (self.bits() & 0xFF) as u8
+        }
+    }
+
+    /// Whether this is an indexed (palettized) format.
+    #[doc(alias = "SDL_ISPIXELFORMAT_INDEXED")]
+    pub fn is_indexed(self) -> bool {
+        !self.is_fourcc()
+            && matches!(
+                self.pixel_type(),
+                PixelType::Index1 | PixelType::Index2 | PixelType::Index4 | PixelType::Index8
+            )
+    }
+
+    /// Whether this is a packed true-color format (channels packed into a
+    /// single 8/16/32-bit integer).
+    #[doc(alias = "SDL_ISPIXELFORMAT_PACKED")]
+    pub fn is_packed(self) -> bool {
+        !self.is_fourcc()
+            && matches!(
+                self.pixel_type(),
+                PixelType::Packed8 | PixelType::Packed16 | PixelType::Packed32
+            )
+    }
+
+    /// Whether this is an array true-color format (one array element per
+    /// channel).
+    #[doc(alias = "SDL_ISPIXELFORMAT_ARRAY")]
+    pub fn is_array(self) -> bool {
+        !self.is_fourcc()
+            && matches!(
+                self.pixel_type(),
+                PixelType::ArrayU8
+                    | PixelType::ArrayU16
+                    | PixelType::ArrayU32
+                    | PixelType::ArrayF16
+                    | PixelType::ArrayF32
+            )
+    }
+
+    /// Whether this format's channel order carries an alpha channel.
+    #[doc(alias = "SDL_ISPIXELFORMAT_ALPHA")]
+    pub fn has_alpha(self) -> bool {
+        match self.pixel_order() {
+            PixelOrder::Packed(order) => matches!(
+                order,
+                PackedOrder::Argb | PackedOrder::Rgba | PackedOrder::Abgr | PackedOrder::Bgra
+            ),
+            PixelOrder::Array(order) => matches!(
+                order,
+                ArrayOrder::Argb | ArrayOrder::Rgba | ArrayOrder::Abgr | ArrayOrder::Bgra
+            ),
+            PixelOrder::None => false,
+        }
+    }
+}
+
+/// The category of a pixel format's layout: indexed, packed, or array.
+/// Decoded from the type nibble of an `SDL_PixelFormat` value.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PixelType {
+    Unknown,
+    Index1,
+    Index2,
+    Index4,
+    Index8,
+    Packed8,
+    Packed16,
+    Packed32,
+    ArrayU8,
+    ArrayU16,
+    ArrayU32,
+    ArrayF16,
+    ArrayF32,
+}
+
+impl PixelType {
+    fn from_bits(bits: u32) -> PixelType {
+        match bits {
+            1 => PixelType::Index1,
+            2 => PixelType::Index4,
+            3 => PixelType::Index8,
+            4 => PixelType::Packed8,
+            5 => PixelType::Packed16,
+            6 => PixelType::Packed32,
+            7 => PixelType::ArrayU8,
+            8 => PixelType::ArrayU16,
+            9 => PixelType::ArrayU32,
+            10 => PixelType::ArrayF16,
+            11 => PixelType::ArrayF32,
+            12 => PixelType::Index2,
+            _ => PixelType::Unknown,
+        }
+    }
+}
+
+/// The channel order of a pixel format, as either a packed or an array
+/// layout. See [`PixelFormat::pixel_order`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PixelOrder {
+    None,
+    Packed(PackedOrder),
+    Array(ArrayOrder),
+}
+
+/// Channel order for [`PixelType::Packed8`]/`Packed16`/`Packed32` formats.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PackedOrder {
+    None,
+    Xrgb,
+    Rgbx,
+    Argb,
+    Rgba,
+    Xbgr,
+    Bgrx,
+    Abgr,
+    Bgra,
+}
+
+impl PackedOrder {
+    fn from_bits(bits: u32) -> PackedOrder {
+        match bits {
+            1 => PackedOrder::Xrgb,
+            2 => PackedOrder::Rgbx,
+            3 => PackedOrder::Argb,
+            4 => PackedOrder::Rgba,
+            5 => PackedOrder::Xbgr,
+            6 => PackedOrder::Bgrx,
+            7 => PackedOrder::Abgr,
+            8 => PackedOrder::Bgra,
+            _ => PackedOrder::None,
+        }
+    }
+}
+
+/// Channel order for [`PixelType::ArrayU8`]/`ArrayU16`/`ArrayU32`/`ArrayF16`/`ArrayF32` formats.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ArrayOrder {
+    None,
+    Rgb,
+    Rgba,
+    Argb,
+    Bgr,
+    Bgra,
+    Abgr,
+}
+
+impl ArrayOrder {
+    fn from_bits(bits: u32) -> ArrayOrder {
+        match bits {
+            1 => ArrayOrder::Rgb,
+            2 => ArrayOrder::Rgba,
+            3 => ArrayOrder::Argb,
+            4 => ArrayOrder::Bgr,
+            5 => ArrayOrder::Bgra,
+            6 => ArrayOrder::Abgr,
+            _ => ArrayOrder::None,
+        }
+    }
+}
+
+/// Bit layout of a packed format (e.g. 5-5-5-1). See
+/// [`PixelFormat::pixel_layout`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PackedLayout {
+    None,
+    Layout332,
+    Layout4444,
+    Layout1555,
+    Layout5551,
+    Layout565,
+    Layout8888,
+    Layout2101010,
+    Layout1010102,
+}
+
+impl PackedLayout {
+    fn from_bits(bits: u32) -> PackedLayout {
+        match bits {
+            1 => PackedLayout::Layout332,
+            2 => PackedLayout::Layout4444,
+            3 => PackedLayout::Layout1555,
+            4 => PackedLayout::Layout5551,
+            5 => PackedLayout::Layout565,
+            6 => PackedLayout::Layout8888,
+            7 => PackedLayout::Layout2101010,
+            8 => PackedLayout::Layout1010102,
+            _ => PackedLayout::None,
+        }
     }
 }
 